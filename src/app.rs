@@ -1,6 +1,10 @@
 // src/app.rs
 use crate::audio::synth::play_sine_wave;
 use crate::ui::terminal::TerminalUI;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 // Add #[allow(unused_imports)] to the module to suppress this specific warning
 #[allow(unused_imports)]
@@ -16,10 +20,14 @@ pub struct App {
 impl App {
     pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
         let debug_messages = Arc::new(Mutex::new(Vec::new()));
+        let debug_generation = Arc::new(AtomicU64::new(0));
 
         log::set_max_level(LevelFilter::Info);
 
-        if let Err(e) = log::set_logger(Box::leak(TuiLogger::new(Arc::clone(&debug_messages)))) {
+        if let Err(e) = log::set_logger(Box::leak(TuiLogger::new(
+            Arc::clone(&debug_messages),
+            Arc::clone(&debug_generation),
+        ))) {
             error!("Failed to set logger: {}", e); // This uses the 'error' import
             return Err(Box::new(std::io::Error::new(
                 std::io::ErrorKind::Other,
@@ -27,7 +35,7 @@ impl App {
             )));
         }
 
-        let ui = TerminalUI::new(Arc::clone(&debug_messages))?;
+        let ui = TerminalUI::new(Arc::clone(&debug_messages), debug_generation)?;
         Ok(Self { ui, debug_messages })
     }
 
@@ -45,14 +53,124 @@ impl App {
     }
 }
 
+// Default size at which the session log is rotated to `maze.log.1`.
+const MAX_LOG_FILE_BYTES: u64 = 1024 * 1024;
+
 // Define the custom logger for the TUI
 pub struct TuiLogger {
     messages: Arc<Mutex<Vec<String>>>,
+    generation: Arc<AtomicU64>, // Bumped on every push so the UI can tell when to redraw
+    log_file: Option<Mutex<LogFile>>, // None if the log file couldn't be opened
 }
 
 impl TuiLogger {
-    pub fn new(messages: Arc<Mutex<Vec<String>>>) -> Box<Self> {
-        Box::new(TuiLogger { messages })
+    // Logs to the UI buffer and to the session log under the config dir.
+    pub fn new(messages: Arc<Mutex<Vec<String>>>, generation: Arc<AtomicU64>) -> Box<Self> {
+        let log_file = log_dir().and_then(|dir| {
+            match LogFile::open(dir.join("maze.log"), MAX_LOG_FILE_BYTES) {
+                Ok(file) => Some(file),
+                Err(e) => {
+                    eprintln!("WARNING: Failed to open session log file: {}", e);
+                    None
+                }
+            }
+        });
+        Self::with_log_file(messages, generation, log_file)
+    }
+
+    pub fn with_log_file(
+        messages: Arc<Mutex<Vec<String>>>,
+        generation: Arc<AtomicU64>,
+        log_file: Option<LogFile>,
+    ) -> Box<Self> {
+        Box::new(TuiLogger {
+            messages,
+            generation,
+            log_file: log_file.map(Mutex::new),
+        })
+    }
+}
+
+// $XDG_CONFIG_HOME/maze, falling back to ~/.config/maze
+fn log_dir() -> Option<PathBuf> {
+    let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute()) // The XDG spec says to ignore relative paths
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config_dir.join("maze"))
+}
+
+// Append-only log file that rotates itself once it exceeds `max_bytes`.
+pub struct LogFile {
+    path: PathBuf,
+    file: Option<File>, // None after rotating until `path` is reopened
+    size: u64,
+    max_bytes: u64,
+    rotate_failure_reported: bool,
+    warning: Option<String>, // For the logger to show in the UI
+}
+
+impl LogFile {
+    pub fn open(path: PathBuf, max_bytes: u64) -> std::io::Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let size = file.metadata()?.len();
+        Ok(Self {
+            path,
+            file: Some(file),
+            size,
+            max_bytes,
+            rotate_failure_reported: false,
+            warning: None,
+        })
+    }
+
+    fn write_line(&mut self, line: &str) -> std::io::Result<()> {
+        if self.size >= self.max_bytes {
+            self.rotate()?;
+        }
+        let file = match &mut self.file {
+            Some(file) => file,
+            // A failed open leaves this None, so it's retried on the next write.
+            None => self.file.insert(
+                OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(&self.path)?,
+            ),
+        };
+        writeln!(file, "{}", line)?;
+        self.size += line.len() as u64 + 1;
+        Ok(())
+    }
+
+    // Keep one previous log around; anything older is discarded.
+    // The UI is in raw mode by now, so failures are reported through `warning`, not stderr.
+    fn rotate(&mut self) -> std::io::Result<()> {
+        match fs::rename(&self.path, self.path.with_extension("log.1")) {
+            Ok(()) => {
+                // The old handle now points at maze.log.1; write_line opens a fresh file.
+                self.file = None;
+            }
+            Err(e) => {
+                // Couldn't move the old log aside (permissions, another instance, ...).
+                // Start over in place rather than dropping every later record.
+                if !self.rotate_failure_reported {
+                    self.warning = Some(format!(
+                        "WARN [maze] Failed to rotate session log file, truncating instead: {}",
+                        e
+                    ));
+                    self.rotate_failure_reported = true;
+                }
+                if let Some(file) = &self.file {
+                    file.set_len(0)?;
+                }
+            }
+        }
+        self.size = 0;
+        Ok(())
     }
 }
 
@@ -64,13 +182,25 @@ impl log::Log for TuiLogger {
     fn log(&self, record: &log::Record) {
         if self.enabled(record.metadata()) {
             let message = format!("{} [{}] {}", record.level(), record.target(), record.args());
+            let mut file_warning = None;
+            if let Some(log_file) = &self.log_file
+                && let Ok(mut log_file) = log_file.lock()
+            {
+                // The UI buffer below still gets the message if the file write fails.
+                let _ = log_file.write_line(&message);
+                file_warning = log_file.warning.take();
+            }
             if let Ok(mut messages_guard) = self.messages.lock() {
                 const MAX_MESSAGES: usize = 20;
-                let current_len = messages_guard.len();
-                if current_len >= MAX_MESSAGES {
-                    messages_guard.drain(0..current_len - MAX_MESSAGES + 1);
+                for message in file_warning.into_iter().chain(std::iter::once(message)) {
+                    let current_len = messages_guard.len();
+                    if current_len >= MAX_MESSAGES {
+                        messages_guard.drain(0..current_len - MAX_MESSAGES + 1);
+                    }
+                    messages_guard.push(message);
+                    // Bumped while the lock is held so readers see it change with the buffer.
+                    self.generation.fetch_add(1, Ordering::Relaxed);
                 }
-                messages_guard.push(message);
             } else {
                 // This 'warn' is implicitly used here if the mutex is poisoned.
                 // We're using eprintln! as a fallback in this critical scenario.
@@ -83,6 +213,116 @@ impl log::Log for TuiLogger {
     }
 
     fn flush(&self) {
-        // No-op for this logger, as messages are pushed immediately
+        // The in-memory buffer needs no flushing; only the log file does.
+        if let Some(log_file) = &self.log_file
+            && let Ok(mut log_file) = log_file.lock()
+            && let Some(file) = &mut log_file.file
+        {
+            let _ = file.flush();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use log::Log;
+
+    // Fresh, empty directory under the system temp dir for one test.
+    fn temp_log_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("maze-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn logger_writes_records_to_file_and_rotates_past_cap() {
+        let dir = temp_log_dir("log-rotation");
+        let path = dir.join("maze.log");
+        // Each record is 22 bytes, so the file rotates once after the fifth one.
+        let log_file = LogFile::open(path.clone(), 100).unwrap();
+        let messages = Arc::new(Mutex::new(Vec::new()));
+        let generation = Arc::new(AtomicU64::new(0));
+        let logger = TuiLogger::with_log_file(
+            Arc::clone(&messages),
+            Arc::clone(&generation),
+            Some(log_file),
+        );
+        log::set_max_level(LevelFilter::Info);
+
+        const N: usize = 10;
+        for i in 0..N {
+            logger.log(
+                &log::Record::builder()
+                    .level(log::Level::Info)
+                    .target("test")
+                    .args(format_args!("message {}", i))
+                    .build(),
+            );
+        }
+        logger.flush();
+
+        let current = fs::read_to_string(&path).unwrap();
+        let rotated = fs::read_to_string(path.with_extension("log.1")).unwrap();
+        assert_eq!(rotated.lines().count(), 5);
+        let all_lines: Vec<&str> = rotated.lines().chain(current.lines()).collect();
+        assert_eq!(all_lines.len(), N);
+        for (i, line) in all_lines.iter().enumerate() {
+            assert_eq!(*line, format!("INFO [test] message {}", i));
+        }
+        assert_eq!(messages.lock().unwrap().len(), N);
+        assert_eq!(generation.load(Ordering::Relaxed), N as u64);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn log_file_truncates_in_place_when_rotation_fails() {
+        let dir = temp_log_dir("log-rotation-failure");
+        let path = dir.join("maze.log");
+        let rotated_path = path.with_extension("log.1");
+        // A non-empty directory in the way makes the rename fail.
+        fs::create_dir_all(rotated_path.join("blocker")).unwrap();
+        let mut log_file = LogFile::open(path.clone(), 100).unwrap();
+
+        for i in 0..12 {
+            log_file
+                .write_line(&format!("INFO [test] message {:02}", i))
+                .unwrap();
+        }
+        log_file.file.as_mut().unwrap().flush().unwrap();
+
+        let current = fs::read_to_string(&path).unwrap();
+        assert!(current.ends_with("INFO [test] message 11\n"));
+        assert!(current.len() < 2 * 100);
+        // Reported once for the UI, not on every failed rotation.
+        let warning = log_file.warning.take().unwrap();
+        assert!(warning.contains("Failed to rotate session log file"));
+        for i in 12..24 {
+            log_file
+                .write_line(&format!("INFO [test] message {:02}", i))
+                .unwrap();
+        }
+        assert!(log_file.warning.is_none());
+
+        // The rename succeeds but reopening maze.log fails: the rotated log must survive.
+        fs::remove_dir_all(&rotated_path).unwrap();
+        log_file.rotate().unwrap();
+        let rotated = fs::read_to_string(&rotated_path).unwrap();
+        fs::create_dir(&path).unwrap();
+        assert!(log_file.write_line("INFO [test] lost").is_err());
+        assert_eq!(fs::read_to_string(&rotated_path).unwrap(), rotated);
+
+        // Once maze.log can be opened again, logging picks up in a fresh file.
+        fs::remove_dir(&path).unwrap();
+        log_file.write_line("INFO [test] recovered").unwrap();
+        log_file.file.as_mut().unwrap().flush().unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "INFO [test] recovered\n"
+        );
+        assert_eq!(fs::read_to_string(&rotated_path).unwrap(), rotated);
+
+        let _ = fs::remove_dir_all(&dir);
     }
 }
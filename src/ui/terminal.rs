@@ -12,18 +12,81 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
 };
 use std::io::{self, Stdout};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
-use std::time::Duration; // Needed for shared state
+use std::time::{Duration, Instant}; // Needed for shared state
+
+// Minimum time between frames, capping redraws at ~30 FPS; also how long we wait for
+// input when idle before checking for new log output.
+const FRAME_INTERVAL: Duration = Duration::from_millis(1000 / 30);
 
 pub struct TerminalUI {
     terminal: Terminal<CrosstermBackend<Stdout>>,
     debug_messages: Arc<Mutex<Vec<String>>>, // Hold a reference to the shared messages
+    debug_generation: Arc<AtomicU64>,        // Bumped by the logger whenever a message is pushed
+    redraw: RedrawState,
+}
+
+// Decides when the UI needs to be redrawn, kept apart from the terminal so it can be tested.
+#[derive(Debug)]
+struct RedrawState {
+    needs_redraw: bool,
+    drawn_generation: u64,       // Logger generation as of the last draw
+    last_drawn: Option<Instant>, // None until the first frame
+}
+
+impl RedrawState {
+    fn new() -> Self {
+        // Nothing is on screen yet.
+        Self {
+            needs_redraw: true,
+            drawn_generation: 0,
+            last_drawn: None,
+        }
+    }
+
+    // Input and resizes change what's on screen; other events (mouse, focus, ...) don't.
+    fn on_event(&mut self, event: &Event) {
+        if matches!(event, Event::Key(_) | Event::Resize(_, _)) {
+            self.needs_redraw = true;
+        }
+    }
+
+    fn is_dirty(&self, generation: u64) -> bool {
+        self.needs_redraw || generation != self.drawn_generation
+    }
+
+    // A dirty state arriving within FRAME_INTERVAL of the last frame waits for the next one.
+    fn should_draw(&self, generation: u64, now: Instant) -> bool {
+        self.is_dirty(generation)
+            && self
+                .last_drawn
+                .is_none_or(|last_drawn| now >= last_drawn + FRAME_INTERVAL)
+    }
+
+    // How long to wait for input: until the next frame is due if something is pending,
+    // otherwise a full idle interval.
+    fn poll_timeout(&self, generation: u64, now: Instant) -> Duration {
+        match self.last_drawn {
+            Some(last_drawn) if self.is_dirty(generation) => {
+                (last_drawn + FRAME_INTERVAL).saturating_duration_since(now)
+            }
+            _ => FRAME_INTERVAL,
+        }
+    }
+
+    fn mark_drawn(&mut self, generation: u64, now: Instant) {
+        self.needs_redraw = false;
+        self.drawn_generation = generation;
+        self.last_drawn = Some(now);
+    }
 }
 
 impl TerminalUI {
     // Modify new to accept the shared debug_messages
     pub fn new(
         debug_messages: Arc<Mutex<Vec<String>>>,
+        debug_generation: Arc<AtomicU64>,
     ) -> Result<Self, Box<dyn std::error::Error>> {
         enable_raw_mode()?;
         let mut stdout = io::stdout();
@@ -34,6 +97,8 @@ impl TerminalUI {
         Ok(Self {
             terminal,
             debug_messages,
+            debug_generation,
+            redraw: RedrawState::new(),
         }) // Store the Arc
     }
 
@@ -42,101 +107,127 @@ impl TerminalUI {
         F: FnMut(),
     {
         loop {
-            self.terminal.draw(|f| {
-                let overall_area = f.area();
+            if self.redraw.should_draw(
+                self.debug_generation.load(Ordering::Relaxed),
+                Instant::now(),
+            ) {
+                self.draw()?;
+            }
+
+            let timeout = self.redraw.poll_timeout(
+                self.debug_generation.load(Ordering::Relaxed),
+                Instant::now(),
+            );
+            if event::poll(timeout)? {
+                let event = event::read()?;
+                self.redraw.on_event(&event);
+                if let Event::Key(key) = event {
+                    match key.code {
+                        KeyCode::Char('q') => break,
+                        KeyCode::Char(' ') => play_callback(),
+                        _ => {}
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
 
-                let main_layout_chunks = Layout::default()
-                    .direction(Direction::Horizontal)
-                    .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
-                    .split(overall_area);
+    fn draw(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        // Snapshot the messages and their generation together so drawn_generation
+        // matches exactly what ends up on screen.
+        let (debug_text, generation) = match self.debug_messages.lock() {
+            Ok(messages) => (
+                Some(messages.join("\n")),
+                self.debug_generation.load(Ordering::Relaxed),
+            ),
+            // Nothing can be pushed to a poisoned buffer, so the generation is stable.
+            Err(_) => (None, self.debug_generation.load(Ordering::Relaxed)),
+        };
 
-                let main_block_area = main_layout_chunks[0];
-                let info_section_area = main_layout_chunks[1];
+        self.terminal.draw(|f| {
+            let overall_area = f.area();
 
-                // --- Main Block (Left Side) ---
-                let main_block = Block::default()
-                    .title(" Main Controls ")
-                    .borders(Borders::ALL);
-                f.render_widget(main_block, main_block_area);
+            let main_layout_chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
+                .split(overall_area);
 
-                let inner_main_chunks = Layout::default()
-                    .direction(Direction::Vertical)
-                    .margin(2)
-                    .constraints([Constraint::Length(3), Constraint::Min(0)].as_ref())
-                    .split(main_block_area);
-
-                let paragraph =
-                    Paragraph::new("Press SPACE to play 440Hz sine wave.\nPress 'q' to quit.")
-                        .style(
-                            Style::default()
-                                .fg(Color::White)
-                                .add_modifier(Modifier::BOLD),
-                        );
-                f.render_widget(paragraph, inner_main_chunks[0]);
-
-                // --- Info Section (Right Side) ---
-                let info_layout_chunks = Layout::default()
-                    .direction(Direction::Vertical)
-                    .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-                    .split(info_section_area);
+            let main_block_area = main_layout_chunks[0];
+            let info_section_area = main_layout_chunks[1];
+
+            // --- Main Block (Left Side) ---
+            let main_block = Block::default()
+                .title(" Main Controls ")
+                .borders(Borders::ALL);
+            f.render_widget(main_block, main_block_area);
+
+            let inner_main_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .margin(2)
+                .constraints([Constraint::Length(3), Constraint::Min(0)].as_ref())
+                .split(main_block_area);
+
+            let paragraph =
+                Paragraph::new("Press SPACE to play 440Hz sine wave.\nPress 'q' to quit.").style(
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                );
+            f.render_widget(paragraph, inner_main_chunks[0]);
 
-                let selected_info_area = info_layout_chunks[0];
-                let debug_block_area = info_layout_chunks[1];
+            // --- Info Section (Right Side) ---
+            let info_layout_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(info_section_area);
 
-                // Render Selected Info Block
-                let selected_info_block = Block::default()
-                    .title(" Selected Info ")
-                    .borders(Borders::ALL);
-                f.render_widget(selected_info_block, selected_info_area);
+            let selected_info_area = info_layout_chunks[0];
+            let debug_block_area = info_layout_chunks[1];
 
-                let inner_selected_info_chunks = Layout::default()
+            // Render Selected Info Block
+            let selected_info_block = Block::default()
+                .title(" Selected Info ")
+                .borders(Borders::ALL);
+            f.render_widget(selected_info_block, selected_info_area);
+
+            let inner_selected_info_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .margin(1)
+                .constraints([Constraint::Min(0)].as_ref())
+                .split(selected_info_area);
+            let selected_info_paragraph = Paragraph::new("Current: Sine Wave (440Hz)")
+                .style(Style::default().fg(Color::Green));
+            f.render_widget(selected_info_paragraph, inner_selected_info_chunks[0]);
+
+            // Render Debug Block
+            let debug_block = Block::default().title(" Debug Info ").borders(Borders::ALL);
+            f.render_widget(debug_block, debug_block_area);
+
+            // --- IMPORTANT: Render debug messages here ---
+            if let Some(debug_text) = debug_text {
+                let debug_paragraph =
+                    Paragraph::new(debug_text).style(Style::default().fg(Color::Yellow));
+                let inner_debug_chunks = Layout::default()
                     .direction(Direction::Vertical)
                     .margin(1)
                     .constraints([Constraint::Min(0)].as_ref())
-                    .split(selected_info_area);
-                let selected_info_paragraph = Paragraph::new("Current: Sine Wave (440Hz)")
-                    .style(Style::default().fg(Color::Green));
-                f.render_widget(selected_info_paragraph, inner_selected_info_chunks[0]);
-
-                // Render Debug Block
-                let debug_block = Block::default().title(" Debug Info ").borders(Borders::ALL);
-                f.render_widget(debug_block, debug_block_area);
-
-                // --- IMPORTANT: Render debug messages here ---
-                if let Ok(messages) = self.debug_messages.lock() {
-                    let debug_text = messages.join("\n");
-                    let debug_paragraph =
-                        Paragraph::new(debug_text).style(Style::default().fg(Color::Yellow));
-                    let inner_debug_chunks = Layout::default()
-                        .direction(Direction::Vertical)
-                        .margin(1)
-                        .constraints([Constraint::Min(0)].as_ref())
-                        .split(debug_block_area);
-                    f.render_widget(debug_paragraph, inner_debug_chunks[0]);
-                } else {
-                    // Handle case where mutex is poisoned (e.g., panic in another thread)
-                    let debug_paragraph = Paragraph::new("Error: Debug message buffer locked!")
-                        .style(Style::default().fg(Color::Red));
-                    let inner_debug_chunks = Layout::default()
-                        .direction(Direction::Vertical)
-                        .margin(1)
-                        .constraints([Constraint::Min(0)].as_ref())
-                        .split(debug_block_area);
-                    f.render_widget(debug_paragraph, inner_debug_chunks[0]);
-                }
-                // --- End debug message rendering ---
-            })?;
-
-            if event::poll(Duration::from_millis(100))? {
-                if let Event::Key(key) = event::read()? {
-                    match key.code {
-                        KeyCode::Char('q') => break,
-                        KeyCode::Char(' ') => play_callback(),
-                        _ => {}
-                    }
-                }
+                    .split(debug_block_area);
+                f.render_widget(debug_paragraph, inner_debug_chunks[0]);
+            } else {
+                // Handle case where mutex is poisoned (e.g., panic in another thread)
+                let debug_paragraph = Paragraph::new("Error: Debug message buffer locked!")
+                    .style(Style::default().fg(Color::Red));
+                let inner_debug_chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .margin(1)
+                    .constraints([Constraint::Min(0)].as_ref())
+                    .split(debug_block_area);
+                f.render_widget(debug_paragraph, inner_debug_chunks[0]);
             }
-        }
+            // --- End debug message rendering ---
+        })?;
+        self.redraw.mark_drawn(generation, Instant::now());
         Ok(())
     }
 }
@@ -148,3 +239,79 @@ impl Drop for TerminalUI {
         let _ = self.terminal.show_cursor();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyEvent, KeyModifiers};
+
+    fn drawn_at(generation: u64, now: Instant) -> RedrawState {
+        let mut redraw = RedrawState::new();
+        redraw.mark_drawn(generation, now);
+        redraw
+    }
+
+    fn key_event() -> Event {
+        Event::Key(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn first_frame_is_drawn() {
+        assert!(RedrawState::new().should_draw(0, Instant::now()));
+    }
+
+    #[test]
+    fn idle_tick_does_not_redraw() {
+        let t0 = Instant::now();
+        let mut redraw = drawn_at(3, t0);
+        let later = t0 + 2 * FRAME_INTERVAL;
+        assert!(!redraw.should_draw(3, later));
+        redraw.on_event(&Event::FocusGained);
+        assert!(!redraw.should_draw(3, later));
+        assert_eq!(redraw.poll_timeout(3, later), FRAME_INTERVAL);
+    }
+
+    #[test]
+    fn key_and_resize_request_redraw() {
+        let t0 = Instant::now();
+        let later = t0 + FRAME_INTERVAL;
+        let mut redraw = drawn_at(3, t0);
+        redraw.on_event(&key_event());
+        assert!(redraw.should_draw(3, later));
+
+        let mut redraw = drawn_at(3, t0);
+        redraw.on_event(&Event::Resize(80, 24));
+        assert!(redraw.should_draw(3, later));
+
+        redraw.mark_drawn(3, later);
+        assert!(!redraw.should_draw(3, later + FRAME_INTERVAL));
+    }
+
+    #[test]
+    fn new_log_message_requests_redraw() {
+        let t0 = Instant::now();
+        let redraw = drawn_at(3, t0);
+        assert!(redraw.should_draw(4, t0 + FRAME_INTERVAL));
+    }
+
+    #[test]
+    fn redraws_are_capped_at_the_frame_rate() {
+        let t0 = Instant::now();
+        let mut redraw = drawn_at(3, t0);
+        let halfway = t0 + FRAME_INTERVAL / 2;
+
+        // A burst of key repeats right after a frame waits for the next one...
+        for _ in 0..10 {
+            redraw.on_event(&key_event());
+        }
+        assert!(!redraw.should_draw(3, halfway));
+        assert_eq!(
+            redraw.poll_timeout(3, halfway),
+            FRAME_INTERVAL - FRAME_INTERVAL / 2
+        );
+
+        // ...and is drawn once it's due.
+        assert!(redraw.should_draw(3, t0 + FRAME_INTERVAL));
+        assert_eq!(redraw.poll_timeout(3, t0 + FRAME_INTERVAL), Duration::ZERO);
+    }
+}